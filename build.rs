@@ -1,4 +1,15 @@
-//! Build script to fetch GraphQL schema from Apollo Studio.
+//! Build script that makes sure `src/graphql/schema.graphql` exists before
+//! codegen runs.
+//!
+//! Precedence:
+//! 1. `UNRAID_INTROSPECT_URL` (+ `UNRAID_API_KEY`) set: fetch the schema by
+//!    running a standard introspection query against that server's
+//!    `/graphql` endpoint and overwrite the committed file. No Rover or
+//!    Apollo account needed.
+//! 2. Otherwise, if the schema is already committed: use it as-is. This is
+//!    the default for normal builds, which need no network access at all.
+//! 3. Otherwise, fall back to `rover graph fetch Unraid-API@current`, for
+//!    maintainers still refreshing the schema from Apollo Studio.
 
 #![allow(clippy::expect_used, clippy::panic, missing_docs)]
 
@@ -7,31 +18,302 @@ use std::fs;
 use std::path::Path;
 use std::process::Command;
 
+const INTROSPECTION_QUERY: &str = r#"
+query IntrospectSchema {
+  __schema {
+    types {
+      kind
+      name
+      fields(includeDeprecated: true) {
+        name
+        args {
+          name
+          type { ...TypeRef }
+          defaultValue
+        }
+        type { ...TypeRef }
+      }
+      inputFields {
+        name
+        type { ...TypeRef }
+        defaultValue
+      }
+      interfaces { ...TypeRef }
+      enumValues(includeDeprecated: true) { name }
+      possibleTypes { ...TypeRef }
+    }
+  }
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType {
+    kind
+    name
+    ofType {
+      kind
+      name
+      ofType {
+        kind
+        name
+        ofType {
+          kind
+          name
+        }
+      }
+    }
+  }
+}
+"#;
+
+const BUILTIN_SCALARS: &[&str] = &["String", "Int", "Float", "Boolean", "ID"];
+
 fn main() {
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
     let schema_path = Path::new(&manifest_dir).join("src/graphql/schema.graphql");
 
-    // Run rover to fetch the schema from Apollo GraphQL
+    println!("cargo::rerun-if-env-changed=UNRAID_INTROSPECT_URL");
+    println!("cargo::rerun-if-env-changed=UNRAID_API_KEY");
+    println!("cargo::rerun-if-env-changed=APOLLO_KEY");
+
+    if let Ok(url) = env::var("UNRAID_INTROSPECT_URL") {
+        let api_key = env::var("UNRAID_API_KEY")
+            .expect("UNRAID_API_KEY must be set alongside UNRAID_INTROSPECT_URL");
+        introspect_schema(&url, &api_key, &schema_path);
+        return;
+    }
+
+    if schema_path.exists() {
+        // Committed schema is the default: no network needed.
+        println!("cargo::rerun-if-changed={}", schema_path.display());
+        return;
+    }
+
+    fetch_schema_via_rover(&schema_path);
+}
+
+/// Fetch the schema from Apollo Studio via the Rover CLI (legacy path).
+fn fetch_schema_via_rover(schema_path: &Path) {
     let output = Command::new("rover")
         .args(["graph", "fetch", "Unraid-API@current"])
         .output()
-        .expect("Failed to execute rover. Is it installed? (https://www.apollographql.com/docs/rover/getting-started)");
+        .expect(
+            "No schema.graphql is committed and rover is not installed. \
+            Either set UNRAID_INTROSPECT_URL (+ UNRAID_API_KEY) to fetch the schema \
+            from a running Unraid server, or install rover \
+            (https://www.apollographql.com/docs/rover/getting-started) and set APOLLO_KEY.",
+        );
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         panic!(
             "rover graph fetch failed:\n{stderr}\n\
-            Make sure APOLLO_KEY is set and you have access to the Unraid-API graph."
+            Make sure APOLLO_KEY is set and you have access to the Unraid-API graph, \
+            or set UNRAID_INTROSPECT_URL instead."
         );
     }
 
     let schema = String::from_utf8(output.stdout).expect("Invalid UTF-8 in schema output");
+    fs::write(schema_path, schema).expect("Failed to write schema.graphql");
+    println!("cargo::rerun-if-changed={}", schema_path.display());
+}
+
+/// Fetch the schema from a live Unraid server via GraphQL introspection and
+/// render it to SDL at `schema_path`.
+fn introspect_schema(url: &str, api_key: &str, schema_path: &Path) {
+    let client = reqwest::blocking::Client::builder()
+        .danger_accept_invalid_certs(true) // Unraid often uses self-signed certs
+        .build()
+        .expect("Failed to create introspection HTTP client");
 
-    // Write the schema to the source directory
-    fs::write(&schema_path, schema).expect("Failed to write schema.graphql");
+    let response = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("x-api-key", api_key)
+        .json(&serde_json::json!({ "query": INTROSPECTION_QUERY }))
+        .send()
+        .unwrap_or_else(|err| panic!("Failed to send introspection query to {url}: {err}"));
 
-    // Tell Cargo to rerun this script if the schema file is deleted
+    let body: serde_json::Value = response
+        .json()
+        .expect("Failed to parse introspection response as JSON");
+
+    if let Some(errors) = body.get("errors") {
+        panic!("Introspection query returned errors: {errors}");
+    }
+
+    let schema = body
+        .get("data")
+        .and_then(|data| data.get("__schema"))
+        .expect("Introspection response missing data.__schema");
+
+    let sdl = render_sdl(schema);
+    fs::write(schema_path, sdl).expect("Failed to write schema.graphql");
     println!("cargo::rerun-if-changed={}", schema_path.display());
-    // Also rerun if APOLLO_KEY changes (allows refreshing schema)
-    println!("cargo::rerun-if-env-changed=APOLLO_KEY");
+}
+
+/// Render an introspection `__schema` value as a GraphQL SDL document.
+fn render_sdl(schema: &serde_json::Value) -> String {
+    let mut out = String::new();
+
+    let types = schema
+        .get("types")
+        .and_then(|t| t.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    for ty in &types {
+        let Some(name) = ty.get("name").and_then(|n| n.as_str()) else {
+            continue;
+        };
+        if name.starts_with("__") {
+            continue;
+        }
+
+        match ty.get("kind").and_then(|k| k.as_str()) {
+            Some("SCALAR") => {
+                if !BUILTIN_SCALARS.contains(&name) {
+                    out.push_str(&format!("scalar {name}\n\n"));
+                }
+            }
+            Some("OBJECT") => {
+                out.push_str(&format!("type {name}{} {{\n", render_interfaces(ty)));
+                out.push_str(&render_fields(ty));
+                out.push_str("}\n\n");
+            }
+            Some("INTERFACE") => {
+                out.push_str(&format!("interface {name} {{\n"));
+                out.push_str(&render_fields(ty));
+                out.push_str("}\n\n");
+            }
+            Some("UNION") => {
+                let members = ty
+                    .get("possibleTypes")
+                    .and_then(|p| p.as_array())
+                    .map(|types| {
+                        types
+                            .iter()
+                            .filter_map(|t| t.get("name").and_then(|n| n.as_str()))
+                            .collect::<Vec<_>>()
+                            .join(" | ")
+                    })
+                    .unwrap_or_default();
+                out.push_str(&format!("union {name} = {members}\n\n"));
+            }
+            Some("ENUM") => {
+                let values = ty
+                    .get("enumValues")
+                    .and_then(|v| v.as_array())
+                    .map(|values| {
+                        values
+                            .iter()
+                            .filter_map(|v| v.get("name").and_then(|n| n.as_str()))
+                            .map(|v| format!("  {v}"))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    })
+                    .unwrap_or_default();
+                out.push_str(&format!("enum {name} {{\n{values}\n}}\n\n"));
+            }
+            Some("INPUT_OBJECT") => {
+                out.push_str(&format!("input {name} {{\n"));
+                out.push_str(&render_input_fields(ty));
+                out.push_str("}\n\n");
+            }
+            _ => {}
+        }
+    }
+
+    out
+}
+
+fn render_interfaces(ty: &serde_json::Value) -> String {
+    let interfaces = ty
+        .get("interfaces")
+        .and_then(|i| i.as_array())
+        .map(|interfaces| {
+            interfaces
+                .iter()
+                .filter_map(|i| i.get("name").and_then(|n| n.as_str()))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    if interfaces.is_empty() {
+        String::new()
+    } else {
+        format!(" implements {}", interfaces.join(" & "))
+    }
+}
+
+fn render_fields(ty: &serde_json::Value) -> String {
+    let Some(fields) = ty.get("fields").and_then(|f| f.as_array()) else {
+        return String::new();
+    };
+
+    fields
+        .iter()
+        .filter_map(|field| {
+            let name = field.get("name")?.as_str()?;
+            let args = render_args(field.get("args").and_then(|a| a.as_array()));
+            let type_str = render_type_ref(field.get("type")?);
+            Some(format!("  {name}{args}: {type_str}\n"))
+        })
+        .collect()
+}
+
+fn render_input_fields(ty: &serde_json::Value) -> String {
+    let Some(fields) = ty.get("inputFields").and_then(|f| f.as_array()) else {
+        return String::new();
+    };
+
+    fields
+        .iter()
+        .filter_map(|field| {
+            let name = field.get("name")?.as_str()?;
+            let type_str = render_type_ref(field.get("type")?);
+            Some(format!("  {name}: {type_str}\n"))
+        })
+        .collect()
+}
+
+fn render_args(args: Option<&Vec<serde_json::Value>>) -> String {
+    let Some(args) = args else {
+        return String::new();
+    };
+    if args.is_empty() {
+        return String::new();
+    }
+
+    let rendered: Vec<String> = args
+        .iter()
+        .filter_map(|arg| {
+            let name = arg.get("name")?.as_str()?;
+            let type_str = render_type_ref(arg.get("type")?);
+            Some(format!("{name}: {type_str}"))
+        })
+        .collect();
+
+    format!("({})", rendered.join(", "))
+}
+
+/// Render a `__Type` introspection value (including nested `NON_NULL`/`LIST`
+/// wrappers) as its SDL type reference, e.g. `[String!]!`.
+fn render_type_ref(type_ref: &serde_json::Value) -> String {
+    match type_ref.get("kind").and_then(|k| k.as_str()) {
+        Some("NON_NULL") => {
+            let inner = type_ref.get("ofType").map(render_type_ref).unwrap_or_default();
+            format!("{inner}!")
+        }
+        Some("LIST") => {
+            let inner = type_ref.get("ofType").map(render_type_ref).unwrap_or_default();
+            format!("[{inner}]")
+        }
+        _ => type_ref
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("Unknown")
+            .to_string(),
+    }
 }
@@ -1,16 +1,110 @@
-use anyhow::{Context, Result};
-use graphql_client::{GraphQLQuery, Response};
+use anyhow::{Context, Result, bail};
+use futures_util::{SinkExt, Stream, StreamExt};
+use futures_util::stream::{SplitSink, SplitStream};
+use graphql_client::{GraphQLQuery, QueryBody, Response};
 use reqwest::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
+use tracing::{debug, trace};
+use uuid::Uuid;
+
+/// Buffered size of the channel feeding [`UnraidClient::subscribe`]'s stream.
+const SUBSCRIPTION_CHANNEL_CAPACITY: usize = 16;
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Retry behavior for [`UnraidClient::execute`], applied to connection
+/// errors, timeouts, and 5xx responses. GraphQL-level errors (a well-formed
+/// response whose `errors` array is non-empty) are never retried.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// Total attempts, including the first. `1` disables retrying.
+    pub max_attempts: u32,
+    /// Base delay for exponential backoff: `base_delay_ms * 2^(attempt - 1)`,
+    /// plus up to 50% jitter.
+    pub base_delay_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 200,
+        }
+    }
+}
+
+/// One error reported by the GraphQL server, with its error code and field
+/// path preserved so callers can branch on them (e.g. distinguish an
+/// auth failure from a not-found) instead of parsing a joined message
+/// string.
+#[derive(Debug, Clone)]
+pub struct GraphQlError {
+    pub message: String,
+    /// `extensions.code`, e.g. `"UNAUTHENTICATED"` or `"NOT_FOUND"`.
+    pub code: Option<String>,
+    pub path: Option<Vec<String>>,
+    pub extensions: Option<HashMap<String, serde_json::Value>>,
+}
+
+impl From<graphql_client::Error> for GraphQlError {
+    fn from(err: graphql_client::Error) -> Self {
+        let code = err
+            .extensions
+            .as_ref()
+            .and_then(|ext| ext.get("code"))
+            .and_then(|code| code.as_str())
+            .map(str::to_string);
+        let path = err
+            .path
+            .map(|segments| segments.into_iter().map(|segment| segment.to_string()).collect());
+
+        Self {
+            message: err.message,
+            code,
+            path,
+            extensions: err.extensions,
+        }
+    }
+}
+
+/// All errors returned by a single GraphQL response.
+#[derive(Debug, Clone)]
+pub struct GraphQlRequestError(pub Vec<GraphQlError>);
+
+impl std::fmt::Display for GraphQlRequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let messages: Vec<&str> = self.0.iter().map(|err| err.message.as_str()).collect();
+        write!(f, "GraphQL errors: {}", messages.join(", "))
+    }
+}
+
+impl std::error::Error for GraphQlRequestError {}
+
+/// Whether an [`UnraidClient::execute`] attempt can be retried.
+enum AttemptError {
+    /// A connection error, timeout, or 5xx response. Safe to retry.
+    Retryable(anyhow::Error),
+    /// A parse failure or GraphQL-level error. Retrying would return the
+    /// same result.
+    Fatal(anyhow::Error),
+}
 
 pub struct UnraidClient {
     client: Client,
     url: String,
     api_key: String,
+    retry: RetryConfig,
 }
 
 impl UnraidClient {
-    pub fn new(url: String, api_key: String, timeout_secs: u64) -> Result<Self> {
+    pub fn new(url: String, api_key: String, timeout_secs: u64, retry: RetryConfig) -> Result<Self> {
         let client = Client::builder()
             .danger_accept_invalid_certs(true) // Unraid often uses self-signed certs
             .timeout(Duration::from_secs(timeout_secs))
@@ -21,6 +115,7 @@ impl UnraidClient {
             client,
             url,
             api_key,
+            retry,
         })
     }
 
@@ -29,37 +124,301 @@ impl UnraidClient {
         variables: Q::Variables,
     ) -> Result<Q::ResponseData> {
         let body = Q::build_query(variables);
+        let operation = body.operation_name;
+
+        debug!(operation, "Sending GraphQL request");
+        trace!(
+            operation,
+            request = %serde_json::to_string(&body).unwrap_or_default(),
+            "GraphQL request body"
+        );
+
+        let started_at = std::time::Instant::now();
+        let max_attempts = self.retry.max_attempts.max(1);
+
+        for attempt in 1..=max_attempts {
+            if attempt > 1 {
+                let delay = backoff_delay(attempt - 1, self.retry.base_delay_ms);
+                debug!(operation, attempt, delay_ms = delay.as_millis() as u64, "Retrying GraphQL request");
+                tokio::time::sleep(delay).await;
+            }
+
+            match self.execute_attempt::<Q>(&body, operation).await {
+                Ok(data) => {
+                    debug!(
+                        operation,
+                        attempt,
+                        duration_ms = started_at.elapsed().as_millis() as u64,
+                        "GraphQL request completed"
+                    );
+                    return Ok(data);
+                }
+                Err(AttemptError::Fatal(err)) => return Err(err),
+                Err(AttemptError::Retryable(err)) => {
+                    if attempt == max_attempts {
+                        return Err(err);
+                    }
+                    debug!(operation, attempt, error = %err, "Transient GraphQL request failure");
+                }
+            }
+        }
+
+        unreachable!("the loop above always returns on its last attempt")
+    }
 
+    async fn execute_attempt<Q: GraphQLQuery>(
+        &self,
+        body: &QueryBody<Q::Variables>,
+        operation: &str,
+    ) -> Result<Q::ResponseData, AttemptError> {
         let response = self
             .client
             .post(&self.url)
             .header("Content-Type", "application/json")
             .header("x-api-key", &self.api_key)
-            .json(&body)
+            .json(body)
             .send()
             .await
-            .context("Failed to send GraphQL request")?;
+            .map_err(|err| AttemptError::Retryable(anyhow::Error::new(err).context("Failed to send GraphQL request")))?;
+
+        let status = response.status();
+        let response_text = response.text().await.map_err(|err| {
+            AttemptError::Retryable(anyhow::Error::new(err).context("Failed to read GraphQL response body"))
+        })?;
+        trace!(operation, response = %response_text, "GraphQL response body");
+
+        if status.is_server_error() {
+            return Err(AttemptError::Retryable(anyhow::anyhow!(
+                "Unraid server returned {status}: {response_text}"
+            )));
+        }
+
+        let response: Response<Q::ResponseData> = serde_json::from_str(&response_text)
+            .map_err(|err| AttemptError::Fatal(anyhow::Error::new(err).context("Failed to parse GraphQL response")))?;
+
+        if let Some(errors) = &response.errors
+            && !errors.is_empty()
+        {
+            debug!(operation, errors = ?errors, "GraphQL response contained errors");
+        }
+
+        Self::process_response(response).map_err(AttemptError::Fatal)
+    }
 
-        let response: Response<Q::ResponseData> = response
-            .json()
+    /// Open a GraphQL subscription over the `graphql-transport-ws` subprotocol.
+    ///
+    /// Connects to the configured URL with its scheme swapped to `ws`/`wss`,
+    /// completes the `connection_init`/`connection_ack` handshake, and sends
+    /// a `subscribe` message for `Q`. The returned stream yields one
+    /// `Q::ResponseData` per server `next` message, ends with an `Err` on a
+    /// server `error` message, and ends cleanly on `complete`. The
+    /// subscription is torn down with a `complete` frame once the stream is
+    /// dropped (detected as soon as the receiving end is dropped).
+    pub async fn subscribe<Q>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<impl Stream<Item = Result<Q::ResponseData>> + use<Q>>
+    where
+        Q: GraphQLQuery + Send + 'static,
+        Q::Variables: Send,
+        Q::ResponseData: Send + 'static,
+    {
+        let ws_url = to_ws_url(&self.url)?;
+
+        let (ws_stream, _) = connect_async(&ws_url)
             .await
-            .context("Failed to parse GraphQL response")?;
+            .with_context(|| format!("Failed to open subscription WebSocket to {ws_url}"))?;
+        let (mut write, mut read) = ws_stream.split();
+
+        send_frame(
+            &mut write,
+            &serde_json::json!({
+                "type": "connection_init",
+                "payload": { "x-api-key": self.api_key },
+            }),
+        )
+        .await?;
+        wait_for_connection_ack(&mut read).await?;
 
-        Self::process_response(response)
+        let id = Uuid::new_v4().to_string();
+        let payload = Q::build_query(variables);
+        send_frame(
+            &mut write,
+            &serde_json::json!({ "id": id, "type": "subscribe", "payload": payload }),
+        )
+        .await?;
+
+        let (tx, rx) = mpsc::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+        tokio::spawn(drive_subscription::<Q>(id, read, write, tx));
+
+        Ok(ReceiverStream::new(rx))
     }
 
     fn process_response<T>(response: Response<T>) -> Result<T> {
         if let Some(errors) = response.errors
             && !errors.is_empty()
         {
-            let error_messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
-            anyhow::bail!("GraphQL errors: {}", error_messages.join(", "));
+            let errors: Vec<GraphQlError> = errors.into_iter().map(GraphQlError::from).collect();
+            return Err(GraphQlRequestError(errors).into());
         }
 
         response.data.context("No data returned from GraphQL query")
     }
 }
 
+/// Exponential backoff with up to 50% jitter: `base_delay_ms * 2^(attempt - 1)`,
+/// plus a random amount in `[0, that value / 2)`.
+fn backoff_delay(attempt: u32, base_delay_ms: u64) -> Duration {
+    let exponential = base_delay_ms.saturating_mul(1u64 << attempt.saturating_sub(1).min(16));
+
+    let now_nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let jitter = if exponential == 0 {
+        0
+    } else {
+        u64::from(now_nanos) % (exponential / 2).max(1)
+    };
+
+    Duration::from_millis(exponential + jitter)
+}
+
+/// Swap an `http(s)://` server URL for its `ws`/`wss` equivalent.
+fn to_ws_url(url: &str) -> Result<String> {
+    if let Some(rest) = url.strip_prefix("https://") {
+        Ok(format!("wss://{rest}"))
+    } else if let Some(rest) = url.strip_prefix("http://") {
+        Ok(format!("ws://{rest}"))
+    } else {
+        bail!("Unraid server URL must start with http:// or https://, got '{url}'")
+    }
+}
+
+async fn send_frame(write: &mut SplitSink<WsStream, Message>, value: &serde_json::Value) -> Result<()> {
+    write
+        .send(Message::Text(value.to_string()))
+        .await
+        .context("Failed to send subscription frame")
+}
+
+/// The subset of `graphql-transport-ws` server messages we act on.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerFrame {
+    ConnectionAck,
+    Ping,
+    Pong,
+    Next { payload: NextPayload },
+    Error { payload: serde_json::Value },
+    Complete,
+}
+
+#[derive(Debug, Deserialize)]
+struct NextPayload {
+    data: Option<serde_json::Value>,
+}
+
+async fn wait_for_connection_ack(read: &mut SplitStream<WsStream>) -> Result<()> {
+    loop {
+        let message = read
+            .next()
+            .await
+            .context("WebSocket closed before connection_ack")?
+            .context("Failed to read from subscription WebSocket")?;
+
+        let Message::Text(text) = message else {
+            continue;
+        };
+
+        match serde_json::from_str::<ServerFrame>(&text) {
+            Ok(ServerFrame::ConnectionAck) => return Ok(()),
+            Ok(ServerFrame::Error { payload }) => {
+                bail!("GraphQL subscription connection rejected: {payload}")
+            }
+            _ => continue,
+        }
+    }
+}
+
+/// Drive one subscription to completion: forward `next` payloads to `tx`,
+/// answer `ping` with `pong`, and send `complete` for `id` once the stream
+/// ends naturally, on a server `error`/`complete`, or once `tx` closes
+/// because the caller dropped the returned stream.
+async fn drive_subscription<Q>(
+    id: String,
+    mut read: SplitStream<WsStream>,
+    mut write: SplitSink<WsStream, Message>,
+    tx: mpsc::Sender<Result<Q::ResponseData>>,
+) where
+    Q: GraphQLQuery,
+    Q::ResponseData: Send,
+{
+    loop {
+        let message = tokio::select! {
+            message = read.next() => message,
+            // Detect the caller dropping the stream immediately, rather than
+            // waiting for the next server message to learn `tx.send` fails.
+            () = tx.closed() => break,
+        };
+
+        let message = match message {
+            Some(Ok(message)) => message,
+            Some(Err(err)) => {
+                let _ = tx
+                    .send(Err(err).context("Subscription WebSocket error"))
+                    .await;
+                break;
+            }
+            None => break,
+        };
+
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Ping(payload) => {
+                let _ = write.send(Message::Pong(payload)).await;
+                continue;
+            }
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        let frame = match serde_json::from_str::<ServerFrame>(&text) {
+            Ok(frame) => frame,
+            Err(_) => continue,
+        };
+
+        let item = match frame {
+            ServerFrame::Ping => {
+                let pong = serde_json::json!({"type": "pong"}).to_string();
+                let _ = write.send(Message::Text(pong)).await;
+                continue;
+            }
+            ServerFrame::Pong | ServerFrame::ConnectionAck => continue,
+            ServerFrame::Next { payload } => match payload.data {
+                Some(data) => serde_json::from_value::<Q::ResponseData>(data)
+                    .context("Failed to deserialize subscription payload"),
+                None => continue,
+            },
+            ServerFrame::Error { payload } => {
+                let _ = tx
+                    .send(Err(anyhow::anyhow!("GraphQL subscription error: {payload}")))
+                    .await;
+                break;
+            }
+            ServerFrame::Complete => break,
+        };
+
+        if tx.send(item).await.is_err() {
+            break;
+        }
+    }
+
+    let complete = serde_json::json!({"id": id, "type": "complete"}).to_string();
+    let _ = write.send(Message::Text(complete)).await;
+    let _ = write.close().await;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,6 +430,7 @@ mod tests {
             "https://192.168.1.100/graphql".to_string(),
             "test-api-key".to_string(),
             5,
+            RetryConfig::default(),
         );
         assert!(result.is_ok());
     }
@@ -157,4 +517,64 @@ mod tests {
         assert!(err.to_string().contains("error one"));
         assert!(err.to_string().contains("error two"));
     }
+
+    #[test]
+    fn to_ws_url_swaps_https_for_wss() {
+        let url = to_ws_url("https://192.168.1.100/graphql").unwrap();
+        assert_eq!(url, "wss://192.168.1.100/graphql");
+    }
+
+    #[test]
+    fn to_ws_url_swaps_http_for_ws() {
+        let url = to_ws_url("http://192.168.1.100/graphql").unwrap();
+        assert_eq!(url, "ws://192.168.1.100/graphql");
+    }
+
+    #[test]
+    fn to_ws_url_rejects_unsupported_scheme() {
+        let err = to_ws_url("ftp://192.168.1.100").unwrap_err();
+        assert!(err.to_string().contains("http:// or https://"));
+    }
+
+    #[test]
+    fn backoff_delay_grows_exponentially_with_base() {
+        let first = backoff_delay(1, 100).as_millis();
+        let second = backoff_delay(2, 100).as_millis();
+        let third = backoff_delay(3, 100).as_millis();
+
+        assert!((100..150).contains(&first), "first={first}");
+        assert!((200..300).contains(&second), "second={second}");
+        assert!((400..600).contains(&third), "third={third}");
+    }
+
+    #[test]
+    fn graphql_error_extracts_code_from_extensions() {
+        let mut extensions = HashMap::new();
+        extensions.insert(
+            "code".to_string(),
+            serde_json::Value::String("UNAUTHENTICATED".to_string()),
+        );
+
+        let err = GraphQlError::from(Error {
+            message: "not authenticated".to_string(),
+            locations: None,
+            path: None,
+            extensions: Some(extensions),
+        });
+
+        assert_eq!(err.code.as_deref(), Some("UNAUTHENTICATED"));
+        assert_eq!(err.message, "not authenticated");
+    }
+
+    #[test]
+    fn graphql_error_code_is_none_without_extensions() {
+        let err = GraphQlError::from(Error {
+            message: "boom".to_string(),
+            locations: None,
+            path: None,
+            extensions: None,
+        });
+
+        assert!(err.code.is_none());
+    }
 }
@@ -0,0 +1,279 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Subcommand;
+
+use crate::client::UnraidClient;
+use crate::commands::docker::{Container, find_container_id};
+use crate::config::{ComposeContainer, ComposeFile};
+use crate::graphql::{ContainerState, GetDockerContainers, StartDockerContainer, StopDockerContainer};
+use crate::output::{self, OutputFormat};
+
+#[derive(Debug, Subcommand)]
+pub enum ComposeCommands {
+    /// Start every container the manifest marks as running
+    Up {
+        /// Path to the compose manifest (TOML or YAML)
+        #[arg(short, long, default_value = "unraid-compose.toml")]
+        file: PathBuf,
+    },
+    /// Stop every container the manifest manages
+    Down {
+        /// Path to the compose manifest (TOML or YAML)
+        #[arg(short, long, default_value = "unraid-compose.toml")]
+        file: PathBuf,
+    },
+}
+
+/// Outcome of reconciling one manifest entry against the live server.
+#[derive(Debug, Clone, serde::Serialize)]
+struct ReconcileResult {
+    name: String,
+    action: &'static str,
+}
+
+/// What `up`/`down` should do for one manifest entry, given the live
+/// container list. Pure and independent of any network calls so the
+/// found/missing/running/not-running branching is easy to unit test.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ReconcileAction {
+    /// The manifest's `running` field doesn't call for a change in this
+    /// direction; leave the container alone.
+    Skip,
+    /// No container with this name exists on the server.
+    Missing,
+    /// Already in the desired state.
+    Unchanged,
+    /// Needs a mutation to reach the desired state, for container `id`.
+    Mutate(String),
+}
+
+/// Plan `up`'s action for `entry`: start it if the manifest wants it
+/// running and it isn't already.
+fn plan_up(entry: &ComposeContainer, live: &[Container]) -> ReconcileAction {
+    if !entry.running {
+        return ReconcileAction::Skip;
+    }
+
+    let Ok(id) = find_container_id(live, &entry.name) else {
+        return ReconcileAction::Missing;
+    };
+
+    let is_running = live
+        .iter()
+        .any(|c| c.id == id && c.state == ContainerState::RUNNING);
+
+    if is_running {
+        ReconcileAction::Unchanged
+    } else {
+        ReconcileAction::Mutate(id)
+    }
+}
+
+/// Plan `down`'s action for `entry`: stop it if the manifest manages it
+/// (`running = true`) and it's currently running. An entry marked
+/// `running = false` isn't managed by `up` either, so `down` leaves it
+/// alone rather than stopping a container for unrelated reasons.
+fn plan_down(entry: &ComposeContainer, live: &[Container]) -> ReconcileAction {
+    if !entry.running {
+        return ReconcileAction::Skip;
+    }
+
+    let Ok(id) = find_container_id(live, &entry.name) else {
+        return ReconcileAction::Missing;
+    };
+
+    let is_running = live
+        .iter()
+        .any(|c| c.id == id && c.state == ContainerState::RUNNING);
+
+    if is_running {
+        ReconcileAction::Mutate(id)
+    } else {
+        ReconcileAction::Unchanged
+    }
+}
+
+pub async fn handle_compose_command(
+    cmd: ComposeCommands,
+    client: &UnraidClient,
+    format: OutputFormat,
+) -> Result<()> {
+    match cmd {
+        ComposeCommands::Up { file } => compose_up(client, &file, format).await,
+        ComposeCommands::Down { file } => compose_down(client, &file, format).await,
+    }
+}
+
+async fn compose_up(client: &UnraidClient, file: &std::path::Path, format: OutputFormat) -> Result<()> {
+    let manifest = ComposeFile::load(file)?;
+    let live = client
+        .execute::<GetDockerContainers>(crate::graphql::get_docker_containers::Variables {})
+        .await?
+        .docker
+        .containers;
+
+    let mut results = Vec::new();
+    for entry in &manifest.containers {
+        let action = match plan_up(entry, &live) {
+            ReconcileAction::Skip | ReconcileAction::Unchanged => "unchanged",
+            ReconcileAction::Missing => "missing",
+            ReconcileAction::Mutate(id) => {
+                client
+                    .execute::<StartDockerContainer>(
+                        crate::graphql::start_docker_container::Variables { id },
+                    )
+                    .await?;
+                "started"
+            }
+        };
+
+        results.push(ReconcileResult {
+            name: entry.name.clone(),
+            action,
+        });
+    }
+
+    render_summary(&results, format)
+}
+
+async fn compose_down(client: &UnraidClient, file: &std::path::Path, format: OutputFormat) -> Result<()> {
+    let manifest = ComposeFile::load(file)?;
+    let live = client
+        .execute::<GetDockerContainers>(crate::graphql::get_docker_containers::Variables {})
+        .await?
+        .docker
+        .containers;
+
+    let mut results = Vec::new();
+    for entry in &manifest.containers {
+        let action = match plan_down(entry, &live) {
+            ReconcileAction::Skip | ReconcileAction::Unchanged => "unchanged",
+            ReconcileAction::Missing => "missing",
+            ReconcileAction::Mutate(id) => {
+                client
+                    .execute::<StopDockerContainer>(
+                        crate::graphql::stop_docker_container::Variables { id },
+                    )
+                    .await?;
+                "stopped"
+            }
+        };
+
+        results.push(ReconcileResult {
+            name: entry.name.clone(),
+            action,
+        });
+    }
+
+    render_summary(&results, format)
+}
+
+fn render_summary(results: &[ReconcileResult], format: OutputFormat) -> Result<()> {
+    if format != OutputFormat::Table {
+        return output::render(&results, format);
+    }
+
+    for result in results {
+        println!("{}: {}", result.name, result.action);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_container(id: &str, name: &str, state: ContainerState) -> Container {
+        Container {
+            id: id.to_string(),
+            names: vec![name.to_string()],
+            image: "some-image:latest".to_string(),
+            state,
+            status: "Up 2 hours".to_string(),
+            labels: serde_json::json!({}),
+            ports: vec![],
+        }
+    }
+
+    fn manifest_entry(name: &str, running: bool) -> ComposeContainer {
+        ComposeContainer {
+            name: name.to_string(),
+            running,
+            restart: None,
+        }
+    }
+
+    // plan_up tests
+
+    #[test]
+    fn plan_up_skips_entries_not_marked_running() {
+        let live = vec![sample_container("id-1", "plex", ContainerState::EXITED)];
+        let entry = manifest_entry("plex", false);
+
+        assert_eq!(plan_up(&entry, &live), ReconcileAction::Skip);
+    }
+
+    #[test]
+    fn plan_up_reports_missing_when_not_found_on_server() {
+        let entry = manifest_entry("plex", true);
+
+        assert_eq!(plan_up(&entry, &[]), ReconcileAction::Missing);
+    }
+
+    #[test]
+    fn plan_up_is_unchanged_when_already_running() {
+        let live = vec![sample_container("id-1", "plex", ContainerState::RUNNING)];
+        let entry = manifest_entry("plex", true);
+
+        assert_eq!(plan_up(&entry, &live), ReconcileAction::Unchanged);
+    }
+
+    #[test]
+    fn plan_up_mutates_when_stopped() {
+        let live = vec![sample_container("id-1", "plex", ContainerState::EXITED)];
+        let entry = manifest_entry("plex", true);
+
+        assert_eq!(
+            plan_up(&entry, &live),
+            ReconcileAction::Mutate("id-1".to_string())
+        );
+    }
+
+    // plan_down tests
+
+    #[test]
+    fn plan_down_skips_entries_not_marked_running() {
+        let live = vec![sample_container("id-1", "plex", ContainerState::RUNNING)];
+        let entry = manifest_entry("plex", false);
+
+        assert_eq!(plan_down(&entry, &live), ReconcileAction::Skip);
+    }
+
+    #[test]
+    fn plan_down_reports_missing_when_not_found_on_server() {
+        let entry = manifest_entry("plex", true);
+
+        assert_eq!(plan_down(&entry, &[]), ReconcileAction::Missing);
+    }
+
+    #[test]
+    fn plan_down_is_unchanged_when_already_stopped() {
+        let live = vec![sample_container("id-1", "plex", ContainerState::EXITED)];
+        let entry = manifest_entry("plex", true);
+
+        assert_eq!(plan_down(&entry, &live), ReconcileAction::Unchanged);
+    }
+
+    #[test]
+    fn plan_down_mutates_when_running() {
+        let live = vec![sample_container("id-1", "plex", ContainerState::RUNNING)];
+        let entry = manifest_entry("plex", true);
+
+        assert_eq!(
+            plan_down(&entry, &live),
+            ReconcileAction::Mutate("id-1".to_string())
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use clap::Subcommand;
+use serde::Serialize;
 
 use crate::config::Config;
+use crate::output::{self, OutputFormat};
 
 #[derive(Debug, Subcommand)]
 pub enum ConfigCommands {
@@ -28,14 +30,21 @@ pub enum ConfigCommands {
     },
     /// List all configured servers
     List,
+    /// Move any plaintext API keys from the config file into the OS keyring
+    MigrateSecrets,
 }
 
-pub fn handle_config_command(cmd: ConfigCommands) -> Result<()> {
+pub fn handle_config_command(
+    cmd: ConfigCommands,
+    format: OutputFormat,
+    reveal_secrets: bool,
+) -> Result<()> {
     match cmd {
         ConfigCommands::Add { name, url, api_key } => add_server(&name, url, api_key),
         ConfigCommands::Remove { name } => remove_server(&name),
         ConfigCommands::Default { name } => set_default(&name),
-        ConfigCommands::List => list_servers(),
+        ConfigCommands::List => list_servers(format, reveal_secrets),
+        ConfigCommands::MigrateSecrets => migrate_secrets(),
     }
 }
 
@@ -43,7 +52,19 @@ fn add_server(name: &str, url: String, api_key: String) -> Result<()> {
     let mut config = Config::load()?;
 
     let is_first = config.servers.is_empty();
-    config.add_server(name.to_string(), url, api_key);
+
+    let stored_key = match crate::keyring::store(name, &api_key) {
+        Ok(()) => None,
+        Err(err) => {
+            eprintln!(
+                "Warning: could not store API key in the OS keyring ({err}); \
+                saving it in the config file instead."
+            );
+            Some(api_key)
+        }
+    };
+
+    config.add_server(name.to_string(), url, stored_key);
 
     // Set as default if it's the first server
     if is_first {
@@ -64,6 +85,7 @@ fn remove_server(name: &str) -> Result<()> {
     let mut config = Config::load()?;
 
     if config.remove_server(name) {
+        let _ = crate::keyring::delete(name);
         config.save()?;
         println!("Server '{name}' removed successfully.");
     } else {
@@ -73,6 +95,40 @@ fn remove_server(name: &str) -> Result<()> {
     Ok(())
 }
 
+fn migrate_secrets() -> Result<()> {
+    let mut config = Config::load()?;
+
+    let mut migrated = 0;
+    let mut failed = 0;
+
+    for (name, server) in &mut config.servers {
+        let Some(api_key) = server.api_key.take() else {
+            continue;
+        };
+
+        match crate::keyring::store(name, &api_key) {
+            Ok(()) => migrated += 1,
+            Err(err) => {
+                eprintln!(
+                    "Warning: could not migrate API key for '{name}' ({err}); \
+                    leaving it in the config file."
+                );
+                server.api_key = Some(api_key);
+                failed += 1;
+            }
+        }
+    }
+
+    config.save()?;
+
+    println!("Migrated {migrated} server API key(s) to the OS keyring.");
+    if failed > 0 {
+        println!("{failed} server(s) could not be migrated and remain in the config file.");
+    }
+
+    Ok(())
+}
+
 fn set_default(name: &str) -> Result<()> {
     let mut config = Config::load()?;
     config.set_default(name)?;
@@ -81,10 +137,47 @@ fn set_default(name: &str) -> Result<()> {
     Ok(())
 }
 
-fn list_servers() -> Result<()> {
+/// Structured view of a configured server, shared by the table and
+/// JSON/YAML renderers.
+#[derive(Debug, Serialize)]
+struct ServerListEntry {
+    name: String,
+    url: String,
+    api_key: String,
+    default: bool,
+}
+
+fn list_servers(format: OutputFormat, reveal_secrets: bool) -> Result<()> {
     let config = Config::load()?;
 
-    if config.servers.is_empty() {
+    let mut entries: Vec<ServerListEntry> = config
+        .servers
+        .iter()
+        .map(|(name, server)| {
+            let resolved = server
+                .resolve_api_key(name)
+                .unwrap_or_else(|_| "(not set)".to_string());
+            let api_key = if reveal_secrets {
+                resolved
+            } else {
+                mask_api_key(&resolved)
+            };
+
+            ServerListEntry {
+                name: name.clone(),
+                url: server.url.clone(),
+                api_key,
+                default: config.default.as_deref() == Some(name.as_str()),
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+    if format != OutputFormat::Table {
+        return output::render(&entries, format);
+    }
+
+    if entries.is_empty() {
         println!("No servers configured.");
         println!("Use 'unraid config add <name> --url <url> --api-key <key>' to add a server.");
         return Ok(());
@@ -93,16 +186,12 @@ fn list_servers() -> Result<()> {
     println!("Configured servers:");
     println!();
 
-    for (name, server) in &config.servers {
-        let default_marker = if config.default.as_deref() == Some(name) {
-            " (default)"
-        } else {
-            ""
-        };
+    for entry in &entries {
+        let default_marker = if entry.default { " (default)" } else { "" };
 
-        println!("  {name}{default_marker}");
-        println!("    URL: {}", server.url);
-        println!("    API Key: {}", mask_api_key(&server.api_key));
+        println!("  {}{default_marker}", entry.name);
+        println!("    URL: {}", entry.url);
+        println!("    API Key: {}", entry.api_key);
         println!();
     }
 
@@ -1,12 +1,31 @@
+use std::collections::HashMap;
+
 use anyhow::{Result, bail};
 use clap::Subcommand;
+use futures_util::{Stream, StreamExt, stream};
+use serde::Serialize;
 
 use crate::client::UnraidClient;
-use crate::graphql::get_docker_containers::GetDockerContainersDockerContainers as Container;
+use crate::commands::compose::{ComposeCommands, handle_compose_command};
+pub(crate) use crate::graphql::get_docker_containers::GetDockerContainersDockerContainers as Container;
 use crate::graphql::{
-    ContainerState, GetDockerContainers, StartDockerContainer, StopDockerContainer,
-    UpdateDockerContainer,
+    ContainerState, GetDockerContainerInspect, GetDockerContainerLogs, GetDockerContainerStats,
+    GetDockerContainers, StartDockerContainer, StopDockerContainer, UpdateDockerContainer,
 };
+use crate::output::{self, OutputFormat};
+
+/// How often to re-poll for new log lines while `--follow` is active.
+const FOLLOW_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// How often to re-poll container metrics while `docker stats` is streaming.
+const STATS_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Delay between the two samples `--no-stream` takes internally so its
+/// single reported snapshot has a real CPU delta instead of always 0%.
+const STATS_SNAPSHOT_SAMPLE_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// Default `--concurrency` for bulk start/stop/restart/update operations.
+const DEFAULT_CONCURRENCY: usize = 4;
 
 #[derive(Debug, Subcommand)]
 pub enum DockerCommands {
@@ -16,36 +35,145 @@ pub enum DockerCommands {
         /// Show all containers (default: only running)
         #[arg(short, long)]
         all: bool,
+        /// Filter containers, can be repeated (status=running|exited|paused, name=<substring>, image=<substring>, label=<key>=<value>)
+        #[arg(long = "filter", value_name = "KEY=VALUE")]
+        filter: Vec<ContainerFilter>,
+        /// Print each container with a custom template instead of a table, e.g. `{{.Names}}\t{{.Image}}`
+        #[arg(long)]
+        format: Option<String>,
     },
-    /// Start a Docker container
+    /// Start one or more Docker containers
     Start {
-        /// Container name
-        name: String,
+        /// Container names
+        #[arg(conflicts_with = "all")]
+        names: Vec<String>,
+        /// Start every known container
+        #[arg(long)]
+        all: bool,
+        /// Maximum number of containers to operate on concurrently
+        #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+        concurrency: usize,
     },
-    /// Stop a Docker container
+    /// Stop one or more Docker containers
     Stop {
-        /// Container name
-        name: String,
+        /// Container names
+        #[arg(conflicts_with = "all")]
+        names: Vec<String>,
+        /// Stop every known container
+        #[arg(long)]
+        all: bool,
+        /// Maximum number of containers to operate on concurrently
+        #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+        concurrency: usize,
     },
-    /// Restart a Docker container (stop then start)
+    /// Restart one or more Docker containers (stop then start)
     Restart {
+        /// Container names
+        #[arg(conflicts_with = "all")]
+        names: Vec<String>,
+        /// Restart every known container
+        #[arg(long)]
+        all: bool,
+        /// Maximum number of containers to operate on concurrently
+        #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+        concurrency: usize,
+    },
+    /// Update one or more Docker containers to their latest image
+    Update {
+        /// Container names
+        #[arg(conflicts_with = "all")]
+        names: Vec<String>,
+        /// Update every known container
+        #[arg(long)]
+        all: bool,
+        /// Maximum number of containers to operate on concurrently
+        #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+        concurrency: usize,
+    },
+    /// Stream a container's logs
+    Logs {
         /// Container name
         name: String,
+        /// Keep the stream open and print new lines as they arrive
+        #[arg(short, long)]
+        follow: bool,
+        /// Only show the last N lines
+        #[arg(long)]
+        tail: Option<i64>,
+        /// Only show logs since this timestamp (RFC3339 or relative, e.g. "10m")
+        #[arg(long)]
+        since: Option<String>,
+        /// Prefix each line with its timestamp
+        #[arg(long)]
+        timestamps: bool,
     },
-    /// Update a Docker container to the latest image
-    Update {
+    /// Show live resource usage for one or all containers
+    Stats {
+        /// Container name (all containers if omitted)
+        name: Option<String>,
+        /// Print a single snapshot instead of streaming updates
+        #[arg(long)]
+        no_stream: bool,
+    },
+    /// Reconcile containers against a declarative manifest
+    Compose {
+        #[command(subcommand)]
+        command: ComposeCommands,
+    },
+    /// Show full detail for a single container
+    Inspect {
         /// Container name
         name: String,
     },
 }
 
-pub async fn handle_docker_command(cmd: DockerCommands, client: &UnraidClient) -> Result<()> {
+pub async fn handle_docker_command(
+    cmd: DockerCommands,
+    client: &UnraidClient,
+    format: OutputFormat,
+) -> Result<()> {
     match cmd {
-        DockerCommands::ListContainers { all } => list_containers(client, all).await,
-        DockerCommands::Start { name } => start_container(client, &name).await,
-        DockerCommands::Stop { name } => stop_container(client, &name).await,
-        DockerCommands::Restart { name } => restart_container(client, &name).await,
-        DockerCommands::Update { name } => update_container(client, &name).await,
+        DockerCommands::ListContainers {
+            all,
+            filter,
+            format: template,
+        } => list_containers(client, all, &filter, template.as_deref(), format).await,
+        DockerCommands::Start {
+            names,
+            all,
+            concurrency,
+        } => bulk_container_op(client, names, all, concurrency, format, ContainerOp::Start).await,
+        DockerCommands::Stop {
+            names,
+            all,
+            concurrency,
+        } => bulk_container_op(client, names, all, concurrency, format, ContainerOp::Stop).await,
+        DockerCommands::Restart {
+            names,
+            all,
+            concurrency,
+        } => {
+            bulk_container_op(client, names, all, concurrency, format, ContainerOp::Restart).await
+        }
+        DockerCommands::Update {
+            names,
+            all,
+            concurrency,
+        } => bulk_container_op(client, names, all, concurrency, format, ContainerOp::Update).await,
+        DockerCommands::Logs {
+            name,
+            follow,
+            tail,
+            since,
+            timestamps,
+        } => logs_container(client, &name, follow, tail, since, timestamps).await,
+        DockerCommands::Stats { name, no_stream } => {
+            stats_container(client, name.as_deref(), no_stream, format).await
+        }
+        DockerCommands::Compose { command } => {
+            handle_compose_command(command, client, format).await
+        }
+        DockerCommands::Inspect { name } => inspect_container(client, &name, format).await,
     }
 }
 
@@ -58,7 +186,7 @@ async fn resolve_container_id(client: &UnraidClient, name: &str) -> Result<Strin
     find_container_id(&response.docker.containers, name)
 }
 
-fn find_container_id(containers: &[Container], name: &str) -> Result<String> {
+pub(crate) fn find_container_id(containers: &[Container], name: &str) -> Result<String> {
     let name_lower = name.to_lowercase();
 
     for container in containers {
@@ -75,82 +203,604 @@ fn find_container_id(containers: &[Container], name: &str) -> Result<String> {
     );
 }
 
-async fn start_container(client: &UnraidClient, name: &str) -> Result<()> {
-    let id = resolve_container_id(client, name).await?;
-
-    println!("Starting container '{name}'...");
-    let response = client
-        .execute::<StartDockerContainer>(crate::graphql::start_docker_container::Variables { id })
-        .await?;
+/// A bulk container operation dispatched by [`bulk_container_op`].
+#[derive(Debug, Clone, Copy)]
+enum ContainerOp {
+    Start,
+    Stop,
+    Restart,
+    Update,
+}
 
-    let container = response.docker.start;
-    let state = format!("{:?}", container.state).to_lowercase();
-    println!("Container '{name}' is now {state}.");
+impl ContainerOp {
+    /// Present-tense verb used in the bulk failure summary, e.g. "failed to stop".
+    fn verb(self) -> &'static str {
+        match self {
+            ContainerOp::Start => "start",
+            ContainerOp::Stop => "stop",
+            ContainerOp::Restart => "restart",
+            ContainerOp::Update => "update",
+        }
+    }
+}
 
-    Ok(())
+/// Outcome of one container's bulk operation.
+#[derive(Debug, Clone, Serialize)]
+struct BulkOpResult {
+    name: String,
+    ok: bool,
+    detail: String,
 }
 
-async fn stop_container(client: &UnraidClient, name: &str) -> Result<()> {
-    let id = resolve_container_id(client, name).await?;
+/// Run `op` against `names` (or every container when `all` is set),
+/// fanning the GraphQL mutations out concurrently up to `concurrency` at
+/// a time, and print a final per-container success/failure summary.
+///
+/// Resolves every name against a single `GetDockerContainers` fetch
+/// rather than re-querying once per container.
+async fn bulk_container_op(
+    client: &UnraidClient,
+    names: Vec<String>,
+    all: bool,
+    concurrency: usize,
+    format: OutputFormat,
+    op: ContainerOp,
+) -> Result<()> {
+    if names.is_empty() && !all {
+        bail!("specify at least one container name, or pass --all");
+    }
 
-    println!("Stopping container '{name}'...");
     let response = client
-        .execute::<StopDockerContainer>(crate::graphql::stop_docker_container::Variables { id })
+        .execute::<GetDockerContainers>(crate::graphql::get_docker_containers::Variables {})
         .await?;
+    let containers = response.docker.containers;
 
-    let container = response.docker.stop;
-    let state = format!("{:?}", container.state).to_lowercase();
-    println!("Container '{name}' is now {state}.");
+    let targets: Vec<(String, String)> = if all {
+        containers
+            .iter()
+            .map(|c| (display_name(c), c.id.clone()))
+            .collect()
+    } else {
+        names
+            .iter()
+            .map(|name| find_container_id(&containers, name).map(|id| (name.clone(), id)))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let concurrency = concurrency.max(1);
+    let results: Vec<BulkOpResult> = stream::iter(targets)
+        .map(|(name, id)| async move {
+            match run_container_op(client, op, id).await {
+                Ok(state) => BulkOpResult {
+                    name,
+                    ok: true,
+                    detail: format!("{:?}", state).to_lowercase(),
+                },
+                Err(err) => BulkOpResult {
+                    name,
+                    ok: false,
+                    detail: err.to_string(),
+                },
+            }
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+
+    render_bulk_results(&results, format)?;
+
+    let failed = results.iter().filter(|r| !r.ok).count();
+    if failed > 0 {
+        bail!(
+            "{failed} of {} container(s) failed to {}",
+            results.len(),
+            op.verb()
+        );
+    }
 
     Ok(())
 }
 
-async fn update_container(client: &UnraidClient, name: &str) -> Result<()> {
-    let id = resolve_container_id(client, name).await?;
+/// Execute a single container's half of a bulk operation.
+async fn run_container_op(client: &UnraidClient, op: ContainerOp, id: String) -> Result<ContainerState> {
+    match op {
+        ContainerOp::Start => {
+            let response = client
+                .execute::<StartDockerContainer>(crate::graphql::start_docker_container::Variables {
+                    id,
+                })
+                .await?;
+            Ok(response.docker.start.state)
+        }
+        ContainerOp::Stop => {
+            let response = client
+                .execute::<StopDockerContainer>(crate::graphql::stop_docker_container::Variables {
+                    id,
+                })
+                .await?;
+            Ok(response.docker.stop.state)
+        }
+        ContainerOp::Restart => {
+            client
+                .execute::<StopDockerContainer>(crate::graphql::stop_docker_container::Variables {
+                    id: id.clone(),
+                })
+                .await?;
+            let response = client
+                .execute::<StartDockerContainer>(crate::graphql::start_docker_container::Variables {
+                    id,
+                })
+                .await?;
+            Ok(response.docker.start.state)
+        }
+        ContainerOp::Update => {
+            let response = client
+                .execute::<UpdateDockerContainer>(crate::graphql::update_docker_container::Variables {
+                    id,
+                })
+                .await?;
+            Ok(response.docker.update_container.state)
+        }
+    }
+}
 
-    println!("Updating container '{name}'...");
-    let response = client
-        .execute::<UpdateDockerContainer>(crate::graphql::update_docker_container::Variables { id })
-        .await?;
+fn render_bulk_results(results: &[BulkOpResult], format: OutputFormat) -> Result<()> {
+    if format != OutputFormat::Table {
+        return output::render(results, format);
+    }
 
-    let container = response.docker.update_container;
-    let state = format!("{:?}", container.state).to_lowercase();
-    println!("Container '{name}' updated successfully (state: {state}).");
+    for result in results {
+        if result.ok {
+            println!("{}: {}", result.name, result.detail);
+        } else {
+            println!("{}: failed ({})", result.name, result.detail);
+        }
+    }
 
     Ok(())
 }
 
-async fn restart_container(client: &UnraidClient, name: &str) -> Result<()> {
+/// Display name for a container: its first name with the leading `/` stripped.
+fn display_name(container: &Container) -> String {
+    container
+        .names
+        .first()
+        .map_or_else(|| container.id.clone(), |s| s.trim_start_matches('/').to_string())
+}
+
+async fn logs_container(
+    client: &UnraidClient,
+    name: &str,
+    follow: bool,
+    tail: Option<i64>,
+    since: Option<String>,
+    timestamps: bool,
+) -> Result<()> {
     let id = resolve_container_id(client, name).await?;
 
-    println!("Restarting container '{name}'...");
-    client
-        .execute::<StopDockerContainer>(crate::graphql::stop_docker_container::Variables {
-            id: id.clone(),
+    let lines = log_stream(client, id, tail, since, timestamps, follow);
+    tokio::pin!(lines);
+
+    loop {
+        tokio::select! {
+            line = lines.next() => {
+                match line {
+                    Some(Ok(line)) => println!("{line}"),
+                    Some(Err(err)) => return Err(err),
+                    None => return Ok(()),
+                }
+            }
+            _ = tokio::signal::ctrl_c(), if follow => return Ok(()),
+        }
+    }
+}
+
+/// Stream log lines for `id`, polling again for new lines while `follow`
+/// is set.
+///
+/// The Unraid API only exposes logs via request/response, so `--follow`
+/// is implemented as a polling loop that re-fetches the log and skips the
+/// lines already emitted, rather than a true server-push subscription.
+fn log_stream(
+    client: &UnraidClient,
+    id: String,
+    tail: Option<i64>,
+    since: Option<String>,
+    timestamps: bool,
+    follow: bool,
+) -> impl Stream<Item = Result<String>> + '_ {
+    struct State {
+        emitted: usize,
+        pending: std::collections::VecDeque<String>,
+        done: bool,
+        /// `tail` to send on the *next* poll. Each poll's `fetched` is only
+        /// the last `tail` lines, not the full cumulative log, so `skip(emitted)`
+        /// is only valid as long as the server never had to truncate the
+        /// window (i.e. `fetched.len() < tail`). The first time a poll comes
+        /// back with a full window, we drop `tail` for all later polls so
+        /// `fetched` goes back to being the full log; the first such poll
+        /// realigns `emitted` (see `resync`), after which it is a valid cursor.
+        next_tail: Option<i64>,
+        /// Set when `tail` is dropped: `emitted` then counts only lines from
+        /// tail-bounded windows, so the next poll realigns it on `history`.
+        resync: bool,
+        /// Up to `tail` most-recently-queued lines. A poll whose request
+        /// was tail-bounded may come back with a window that starts after
+        /// our cursor (the server dropped older lines to fit it), so
+        /// `skip(emitted)` would silently drop whatever fell in between.
+        /// This lets us instead find where the new window overlaps what
+        /// we've already queued and only take what's genuinely new.
+        history: std::collections::VecDeque<String>,
+    }
+
+    let history_cap = tail.map_or(0, |t| t.max(0) as usize);
+
+    stream::unfold(
+        State {
+            emitted: 0,
+            pending: std::collections::VecDeque::new(),
+            done: false,
+            next_tail: tail,
+            resync: false,
+            history: std::collections::VecDeque::new(),
+        },
+        move |mut state| {
+            let id = id.clone();
+            let since = since.clone();
+            async move {
+                loop {
+                    if let Some(line) = state.pending.pop_front() {
+                        state.emitted += 1;
+                        return Some((Ok(line), state));
+                    }
+
+                    if state.done {
+                        return None;
+                    }
+
+                    let requested_tail = state.next_tail;
+                    let response = match client
+                        .execute::<GetDockerContainerLogs>(
+                            crate::graphql::get_docker_container_logs::Variables {
+                                id: id.clone(),
+                                tail: requested_tail,
+                                since: since.clone(),
+                                timestamps: Some(timestamps),
+                            },
+                        )
+                        .await
+                    {
+                        Ok(response) => response,
+                        Err(err) => return Some((Err(err), state)),
+                    };
+
+                    let fetched = response.docker.logs.lines;
+                    if let Some(requested_tail) = requested_tail
+                        && fetched.len() as i64 >= requested_tail
+                    {
+                        // The window was full, meaning the server may have had to drop
+                        // older lines to fit it. Fetch the full log from here on.
+                        state.next_tail = None;
+                        state.resync = true;
+                    }
+
+                    let new_lines: Vec<String> = if requested_tail.is_some() {
+                        // This poll's request was tail-bounded, so its window
+                        // may not pick up exactly where `emitted` left off.
+                        let overlap = overlap_len(&state.history, &fetched);
+                        fetched[overlap..].to_vec()
+                    } else if std::mem::take(&mut state.resync) {
+                        // First full-log poll: resume after the last queued lines.
+                        let start = fetched
+                            .windows(state.history.len().max(1))
+                            .rposition(|window| state.history.iter().eq(window.iter()))
+                            .map_or(0, |pos| pos + state.history.len());
+                        state.emitted = start;
+                        fetched[start..].to_vec()
+                    } else {
+                        fetched.into_iter().skip(state.emitted).collect()
+                    };
+
+                    if history_cap > 0 {
+                        for line in &new_lines {
+                            state.history.push_back(line.clone());
+                            if state.history.len() > history_cap {
+                                state.history.pop_front();
+                            }
+                        }
+                    }
+
+                    if !follow {
+                        state.done = true;
+                        if new_lines.is_empty() {
+                            return None;
+                        }
+                        state.pending.extend(new_lines);
+                        continue;
+                    }
+
+                    if new_lines.is_empty() {
+                        tokio::time::sleep(FOLLOW_POLL_INTERVAL).await;
+                        continue;
+                    }
+
+                    state.pending.extend(new_lines);
+                }
+            }
+        },
+    )
+}
+
+/// Length of the longest suffix of `history` that matches a prefix of
+/// `fetched`, i.e. how many lines at the start of the new window are
+/// already queued. Both are in chronological (oldest-first) order.
+fn overlap_len(history: &std::collections::VecDeque<String>, fetched: &[String]) -> usize {
+    let history: Vec<&String> = history.iter().collect();
+    let max_overlap = history.len().min(fetched.len());
+    (1..=max_overlap)
+        .rev()
+        .find(|&overlap| {
+            history[history.len() - overlap..]
+                .iter()
+                .zip(&fetched[..overlap])
+                .all(|(h, f)| *h == f)
         })
-        .await?;
+        .unwrap_or(0)
+}
+
+/// One rendered `docker stats` row, with CPU% already computed from the
+/// previous and current sample.
+#[derive(Debug, Clone, Serialize)]
+struct ContainerStatsView {
+    name: String,
+    cpu_percent: f64,
+    mem_usage: i64,
+    mem_limit: i64,
+    mem_percent: f64,
+    net_rx_bytes: i64,
+    net_tx_bytes: i64,
+}
+
+/// Previous cpu/system usage sample for a container, used to compute the
+/// standard `(cpu_delta / system_delta) * online_cpus * 100` percentage.
+#[derive(Debug, Clone, Copy, Default)]
+struct CpuSample {
+    cpu_usage: i64,
+    system_cpu_usage: i64,
+}
+
+async fn stats_container(
+    client: &UnraidClient,
+    name: Option<&str>,
+    no_stream: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    if !no_stream && format != OutputFormat::Table {
+        bail!("--output {format:?} requires --no-stream for a single snapshot");
+    }
+
+    let ids = match name {
+        Some(name) => Some(vec![resolve_container_id(client, name).await?]),
+        None => None,
+    };
+
+    let mut previous: HashMap<String, CpuSample> = HashMap::new();
+
+    if no_stream {
+        // A single sample can't produce a CPU delta, so take a throwaway
+        // one first purely to seed `previous` before the real snapshot below.
+        let seed = client
+            .execute::<GetDockerContainerStats>(
+                crate::graphql::get_docker_container_stats::Variables { ids: ids.clone() },
+            )
+            .await?;
+        for container in &seed.docker.container_stats {
+            previous.insert(
+                container.id.clone(),
+                CpuSample {
+                    cpu_usage: container.cpu_usage,
+                    system_cpu_usage: container.system_cpu_usage,
+                },
+            );
+        }
+        tokio::time::sleep(STATS_SNAPSHOT_SAMPLE_INTERVAL).await;
+    }
+
+    loop {
+        let response = client
+            .execute::<GetDockerContainerStats>(
+                crate::graphql::get_docker_container_stats::Variables { ids: ids.clone() },
+            )
+            .await?;
+
+        let mut rows = Vec::new();
+        for container in response.docker.container_stats {
+            let current = CpuSample {
+                cpu_usage: container.cpu_usage,
+                system_cpu_usage: container.system_cpu_usage,
+            };
+            let cpu_percent = previous
+                .get(&container.id)
+                .map(|prev| cpu_percent(prev, &current, container.online_cpus))
+                .unwrap_or(0.0);
+            previous.insert(container.id.clone(), current);
+
+            let mem_percent = if container.mem_limit > 0 {
+                container.mem_usage as f64 / container.mem_limit as f64 * 100.0
+            } else {
+                0.0
+            };
+
+            rows.push(ContainerStatsView {
+                name: container
+                    .names
+                    .first()
+                    .map_or_else(|| container.id.clone(), |s| s.trim_start_matches('/').to_string()),
+                cpu_percent,
+                mem_usage: container.mem_usage,
+                mem_limit: container.mem_limit,
+                mem_percent,
+                net_rx_bytes: container.network_rx_bytes,
+                net_tx_bytes: container.network_tx_bytes,
+            });
+        }
+
+        if format != OutputFormat::Table {
+            return output::render(&rows, format);
+        }
+
+        print_stats_table(&rows);
+
+        if no_stream {
+            return Ok(());
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(STATS_POLL_INTERVAL) => {}
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+        }
+    }
+}
+
+fn cpu_percent(prev: &CpuSample, current: &CpuSample, online_cpus: i64) -> f64 {
+    let cpu_delta = (current.cpu_usage - prev.cpu_usage) as f64;
+    let system_delta = (current.system_cpu_usage - prev.system_cpu_usage) as f64;
+
+    if system_delta <= 0.0 {
+        return 0.0;
+    }
+
+    // A container restart between polls resets the cumulative `cpu_usage`
+    // counter below its previous value while `system_cpu_usage` keeps
+    // climbing, making `cpu_delta` negative — that's "no comparable
+    // sample", not negative CPU usage. Clamp rather than print a negative
+    // percentage; the call site already re-seeds `previous` with `current`
+    // every round, so the next poll recovers a real delta on its own.
+    ((cpu_delta / system_delta) * online_cpus as f64 * 100.0).max(0.0)
+}
+
+fn print_stats_table(rows: &[ContainerStatsView]) {
+    // Redraw in place rather than scrolling the terminal.
+    print!("\x1B[2J\x1B[H");
+    println!(
+        "{:<30} {:<10} {:<24} {:<20}",
+        "NAME", "CPU %", "MEM USAGE / LIMIT", "NET I/O"
+    );
+    for row in rows {
+        println!(
+            "{:<30} {:<10} {:<24} {:<20}",
+            truncate(&row.name, 29),
+            format!("{:.2}%", row.cpu_percent),
+            format!(
+                "{} / {} ({:.1}%)",
+                format_bytes(row.mem_usage),
+                format_bytes(row.mem_limit),
+                row.mem_percent
+            ),
+            format!(
+                "{} / {}",
+                format_bytes(row.net_rx_bytes),
+                format_bytes(row.net_tx_bytes)
+            )
+        );
+    }
+}
+
+fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1}{}", UNITS[unit])
+}
+
+async fn inspect_container(client: &UnraidClient, name: &str, format: OutputFormat) -> Result<()> {
+    let id = resolve_container_id(client, name).await?;
 
     let response = client
-        .execute::<StartDockerContainer>(crate::graphql::start_docker_container::Variables { id })
+        .execute::<GetDockerContainerInspect>(
+            crate::graphql::get_docker_container_inspect::Variables { id },
+        )
         .await?;
 
-    let container = response.docker.start;
-    let state = format!("{:?}", container.state).to_lowercase();
-    println!("Container '{name}' is now {state}.");
+    let container = response.docker.container;
+
+    if format != OutputFormat::Table {
+        return output::render(&container, format);
+    }
+
+    println!("Id:      {}", container.id);
+    println!(
+        "Name:    {}",
+        container
+            .names
+            .first()
+            .map_or("unnamed", |s| s.trim_start_matches('/'))
+    );
+    println!("Image:   {}", container.image);
+    println!("State:   {:?}", container.state);
+    println!("Status:  {}", container.status);
+    println!("Created: {}", container.created);
+    println!("Command: {}", container.command);
+
+    println!("Env:");
+    for var in &container.env {
+        println!("  {var}");
+    }
+
+    println!("Labels:");
+    if let Some(labels) = container.labels.as_object() {
+        for (key, value) in labels {
+            println!("  {key}={value}");
+        }
+    }
+
+    println!("Mounts:");
+    for mount in &container.mounts {
+        println!(
+            "  {} -> {} ({})",
+            mount.source, mount.destination, mount.mode
+        );
+    }
+
+    println!("Network:");
+    println!("  IP Address: {}", container.network_settings.ip_address);
+    println!("  Gateway:    {}", container.network_settings.gateway);
+    println!("  MAC:        {}", container.network_settings.mac_address);
 
     Ok(())
 }
 
-async fn list_containers(client: &UnraidClient, show_all: bool) -> Result<()> {
+async fn list_containers(
+    client: &UnraidClient,
+    show_all: bool,
+    filters: &[ContainerFilter],
+    format_template: Option<&str>,
+    format: OutputFormat,
+) -> Result<()> {
     let response = client
         .execute::<GetDockerContainers>(crate::graphql::get_docker_containers::Variables {})
         .await?;
 
     let containers = response.docker.containers;
-    let filtered = filter_by_state(containers, show_all);
+    let filtered = filter_containers(containers, show_all, filters);
+
+    if let Some(template) = format_template {
+        for container in &filtered {
+            println!("{}", format_container(template, container));
+        }
+        return Ok(());
+    }
+
+    if format != OutputFormat::Table {
+        return output::render(&filtered, format);
+    }
 
     if filtered.is_empty() {
-        if show_all {
+        if show_all || !filters.is_empty() {
             println!("No containers found.");
         } else {
             println!("No running containers found. Use --all to show all containers.");
@@ -172,18 +822,11 @@ async fn list_containers(client: &UnraidClient, show_all: bool) -> Result<()> {
             .first()
             .map_or("unnamed", |s| s.trim_start_matches('/'));
 
-        let state = match container.state {
-            ContainerState::RUNNING => "running",
-            ContainerState::PAUSED => "paused",
-            ContainerState::EXITED => "exited",
-            ContainerState::Other(ref s) => s.as_str(),
-        };
-
         println!(
             "{:<30} {:<40} {:<10} {:<20}",
             truncate(name, 29),
             truncate(&container.image, 39),
-            state,
+            state_label(&container.state),
             truncate(&container.status, 19)
         );
     }
@@ -191,17 +834,118 @@ async fn list_containers(client: &UnraidClient, show_all: bool) -> Result<()> {
     Ok(())
 }
 
-fn filter_by_state(containers: Vec<Container>, show_all: bool) -> Vec<Container> {
-    if show_all {
-        containers
-    } else {
-        containers
-            .into_iter()
-            .filter(|c| c.state == ContainerState::RUNNING)
-            .collect()
+/// Human-readable label for a container's state, shared by the table
+/// renderer and `--filter status=...` matching.
+fn state_label(state: &ContainerState) -> &str {
+    match state {
+        ContainerState::RUNNING => "running",
+        ContainerState::PAUSED => "paused",
+        ContainerState::EXITED => "exited",
+        ContainerState::Other(ref s) => s.as_str(),
+    }
+}
+
+/// A single `--filter key=value` constraint for `list-containers`.
+///
+/// Mirrors shiplift's `ContainerFilter`: each instance is one predicate,
+/// and [`filter_containers`] ANDs them together over the container list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ContainerFilter {
+    Status(String),
+    Name(String),
+    Image(String),
+    Label(String, String),
+}
+
+impl ContainerFilter {
+    fn matches(&self, container: &Container) -> bool {
+        match self {
+            ContainerFilter::Status(status) => state_label(&container.state) == status,
+            ContainerFilter::Name(substr) => container.names.iter().any(|name| {
+                name.trim_start_matches('/')
+                    .to_lowercase()
+                    .contains(substr)
+            }),
+            ContainerFilter::Image(substr) => container.image.to_lowercase().contains(substr),
+            ContainerFilter::Label(key, value) => container
+                .labels
+                .as_object()
+                .and_then(|labels| labels.get(key))
+                .and_then(|v| v.as_str())
+                .is_some_and(|v| v == value),
+        }
     }
 }
 
+impl std::str::FromStr for ContainerFilter {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (key, value) = s
+            .split_once('=')
+            .ok_or_else(|| anyhow::anyhow!("filter '{s}' must be in key=value form"))?;
+
+        match key {
+            "status" => Ok(ContainerFilter::Status(value.to_lowercase())),
+            "name" => Ok(ContainerFilter::Name(value.to_lowercase())),
+            "image" => Ok(ContainerFilter::Image(value.to_lowercase())),
+            "label" => {
+                let (label_key, label_value) = value.split_once('=').ok_or_else(|| {
+                    anyhow::anyhow!("label filter '{s}' must be in label=key=value form")
+                })?;
+                Ok(ContainerFilter::Label(
+                    label_key.to_string(),
+                    label_value.to_string(),
+                ))
+            }
+            other => bail!("unknown filter key '{other}', expected status, name, image, or label"),
+        }
+    }
+}
+
+/// AND together `--all`, `--filter`, and the default running-only rule.
+///
+/// An explicit `status=` filter takes over from the running-only default,
+/// since the caller is already choosing which states to include.
+fn filter_containers(
+    containers: Vec<Container>,
+    show_all: bool,
+    filters: &[ContainerFilter],
+) -> Vec<Container> {
+    let has_status_filter = filters
+        .iter()
+        .any(|f| matches!(f, ContainerFilter::Status(_)));
+
+    containers
+        .into_iter()
+        .filter(|c| {
+            if !has_status_filter && !show_all && c.state != ContainerState::RUNNING {
+                return false;
+            }
+            filters.iter().all(|f| f.matches(c))
+        })
+        .collect()
+}
+
+/// Render `container` through a minimal subset of Docker's `--format`
+/// template syntax: `{{.Field}}` placeholders are substituted directly,
+/// with `\t`/`\n` escapes expanded so columns can be tab-separated.
+fn format_container(template: &str, container: &Container) -> String {
+    let name = container
+        .names
+        .first()
+        .map_or("unnamed", |s| s.trim_start_matches('/'));
+
+    template
+        .replace("{{.Names}}", name)
+        .replace("{{.Image}}", &container.image)
+        .replace("{{.Status}}", &container.status)
+        .replace("{{.State}}", state_label(&container.state))
+        .replace("{{.ID}}", &container.id)
+        .replace("\\t", "\t")
+        .replace("\\n", "\n")
+}
+
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
         s.to_string()
@@ -220,6 +964,7 @@ mod tests {
             image: "some-image:latest".to_string(),
             state,
             status: "Up 2 hours".to_string(),
+            labels: serde_json::json!({}),
             ports: vec![],
         }
     }
@@ -275,6 +1020,7 @@ mod tests {
             image: "img".to_string(),
             state: ContainerState::RUNNING,
             status: "Up".to_string(),
+            labels: serde_json::json!({}),
             ports: vec![],
         }];
 
@@ -303,6 +1049,7 @@ mod tests {
             image: "img".to_string(),
             state: ContainerState::RUNNING,
             status: "Up".to_string(),
+            labels: serde_json::json!({}),
             ports: vec![],
         }];
 
@@ -310,30 +1057,262 @@ mod tests {
         assert_eq!(result, "id-multi");
     }
 
-    // filter_by_state tests
+    // filter_containers tests
 
     #[test]
-    fn filter_by_state_returns_all_containers_when_show_all_is_true() {
+    fn filter_containers_returns_all_containers_when_show_all_is_true() {
         let containers = sample_containers();
-        let filtered = filter_by_state(containers, true);
+        let filtered = filter_containers(containers, true, &[]);
         assert_eq!(filtered.len(), 4);
     }
 
     #[test]
-    fn filter_by_state_returns_only_running_when_show_all_is_false() {
+    fn filter_containers_returns_only_running_when_show_all_is_false_and_no_filters() {
         let containers = sample_containers();
-        let filtered = filter_by_state(containers, false);
+        let filtered = filter_containers(containers, false, &[]);
         assert_eq!(filtered.len(), 2);
         assert!(filtered.iter().all(|c| c.state == ContainerState::RUNNING));
     }
 
     #[test]
-    fn filter_by_state_returns_empty_when_no_running_containers() {
+    fn filter_containers_returns_empty_when_no_running_containers() {
         let containers = vec![
             sample_container("id-1", "a", ContainerState::EXITED),
             sample_container("id-2", "b", ContainerState::PAUSED),
         ];
-        let filtered = filter_by_state(containers, false);
+        let filtered = filter_containers(containers, false, &[]);
         assert!(filtered.is_empty());
     }
+
+    #[test]
+    fn filter_containers_status_filter_overrides_running_only_default() {
+        let containers = sample_containers();
+        let filters = [ContainerFilter::Status("exited".to_string())];
+        let filtered = filter_containers(containers, false, &filters);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "id-3");
+    }
+
+    #[test]
+    fn filter_containers_name_filter_matches_substring_case_insensitively() {
+        let containers = sample_containers();
+        let filters = ["name=SON".parse::<ContainerFilter>().unwrap()];
+        let filtered = filter_containers(containers, true, &filters);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "id-2");
+    }
+
+    #[test]
+    fn filter_containers_image_filter_matches_substring() {
+        let containers = sample_containers();
+        let filters = [ContainerFilter::Image("some-image".to_string())];
+        let filtered = filter_containers(containers, true, &filters);
+        assert_eq!(filtered.len(), 4);
+    }
+
+    #[test]
+    fn filter_containers_label_filter_matches_exact_value() {
+        let mut containers = sample_containers();
+        containers[0].labels = serde_json::json!({"project": "media"});
+        let filters = [ContainerFilter::Label(
+            "project".to_string(),
+            "media".to_string(),
+        )];
+        let filtered = filter_containers(containers, true, &filters);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "id-1");
+    }
+
+    #[test]
+    fn filter_containers_multiple_filters_and_together() {
+        let mut containers = sample_containers();
+        containers[0].labels = serde_json::json!({"project": "media"});
+        let filters = [
+            ContainerFilter::Status("running".to_string()),
+            ContainerFilter::Label("project".to_string(), "media".to_string()),
+        ];
+        let filtered = filter_containers(containers, false, &filters);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "id-1");
+    }
+
+    // ContainerFilter::from_str tests
+
+    #[test]
+    fn container_filter_parses_status() {
+        let filter: ContainerFilter = "status=RUNNING".parse().unwrap();
+        assert_eq!(filter, ContainerFilter::Status("running".to_string()));
+    }
+
+    #[test]
+    fn container_filter_parses_label() {
+        let filter: ContainerFilter = "label=com.docker.compose.project=myapp".parse().unwrap();
+        assert_eq!(
+            filter,
+            ContainerFilter::Label("com.docker.compose.project".to_string(), "myapp".to_string())
+        );
+    }
+
+    #[test]
+    fn container_filter_rejects_unknown_key() {
+        let err = "owner=bob".parse::<ContainerFilter>().unwrap_err();
+        assert!(err.to_string().contains("unknown filter key"));
+    }
+
+    #[test]
+    fn container_filter_rejects_missing_equals() {
+        let err = "status".parse::<ContainerFilter>().unwrap_err();
+        assert!(err.to_string().contains("key=value"));
+    }
+
+    // format_container tests
+
+    #[test]
+    fn format_container_substitutes_known_fields() {
+        let container = sample_container("id-1", "plex", ContainerState::RUNNING);
+        let rendered = format_container("{{.Names}}\t{{.Image}}", &container);
+        assert_eq!(rendered, "plex\tsome-image:latest");
+    }
+
+    // display_name tests
+
+    #[test]
+    fn display_name_strips_leading_slash() {
+        let container = sample_container("id-1", "/plex", ContainerState::RUNNING);
+        assert_eq!(display_name(&container), "plex");
+    }
+
+    #[test]
+    fn display_name_falls_back_to_id_when_unnamed() {
+        let container = Container {
+            id: "id-unnamed".to_string(),
+            names: vec![],
+            image: "img".to_string(),
+            state: ContainerState::RUNNING,
+            status: "Up".to_string(),
+            labels: serde_json::json!({}),
+            ports: vec![],
+        };
+        assert_eq!(display_name(&container), "id-unnamed");
+    }
+
+    // ContainerOp tests
+
+    #[test]
+    fn container_op_verb_matches_the_operation() {
+        assert_eq!(ContainerOp::Start.verb(), "start");
+        assert_eq!(ContainerOp::Stop.verb(), "stop");
+        assert_eq!(ContainerOp::Restart.verb(), "restart");
+        assert_eq!(ContainerOp::Update.verb(), "update");
+    }
+
+    // overlap_len tests (the dedupe `log_stream` relies on once a
+    // tail-bounded poll's window starts after our cursor)
+
+    #[test]
+    fn overlap_len_finds_the_window_starting_mid_history() {
+        // tail=5, 3 lines already emitted, log grows to 6 total: the next
+        // poll's window is the last 5 lines (l2..l6), which starts after
+        // l1 but overlaps l2/l3 of what's already queued.
+        let history: std::collections::VecDeque<String> =
+            ["l1", "l2", "l3"].into_iter().map(String::from).collect();
+        let fetched: Vec<String> = ["l2", "l3", "l4", "l5", "l6"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let overlap = overlap_len(&history, &fetched);
+        assert_eq!(overlap, 2);
+        // l4 must survive, not be silently dropped like a plain
+        // `skip(emitted)` against this truncated window would do.
+        assert_eq!(fetched[overlap..], ["l4", "l5", "l6"]);
+    }
+
+    #[test]
+    fn overlap_len_is_zero_when_windows_do_not_overlap() {
+        let history: std::collections::VecDeque<String> =
+            ["l1", "l2"].into_iter().map(String::from).collect();
+        let fetched: Vec<String> = ["l5", "l6"].into_iter().map(String::from).collect();
+
+        assert_eq!(overlap_len(&history, &fetched), 0);
+    }
+
+    #[test]
+    fn overlap_len_is_full_history_when_fetched_is_cumulative() {
+        // Once the server stops truncating, `fetched` is the whole log
+        // from the start and should overlap all of history.
+        let history: std::collections::VecDeque<String> =
+            ["l1", "l2", "l3"].into_iter().map(String::from).collect();
+        let fetched: Vec<String> = ["l1", "l2", "l3", "l4"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        assert_eq!(overlap_len(&history, &fetched), 3);
+    }
+
+    // cpu_percent tests
+
+    #[test]
+    fn cpu_percent_computes_delta_ratio_scaled_by_online_cpus() {
+        let prev = CpuSample {
+            cpu_usage: 1_000,
+            system_cpu_usage: 10_000,
+        };
+        let current = CpuSample {
+            cpu_usage: 1_500,
+            system_cpu_usage: 15_000,
+        };
+
+        // cpu_delta=500, system_delta=5000 -> (500/5000) * 2 * 100 = 20%
+        assert_eq!(cpu_percent(&prev, &current, 2), 20.0);
+    }
+
+    #[test]
+    fn cpu_percent_is_zero_when_system_delta_is_not_positive() {
+        let prev = CpuSample {
+            cpu_usage: 1_000,
+            system_cpu_usage: 10_000,
+        };
+        let unchanged = prev;
+
+        assert_eq!(cpu_percent(&prev, &unchanged, 4), 0.0);
+    }
+
+    #[test]
+    fn cpu_percent_clamps_to_zero_when_container_restarted() {
+        // A restart resets the cumulative `cpu_usage` counter below its
+        // previous value while `system_cpu_usage` keeps climbing, which
+        // would otherwise compute a negative percentage.
+        let prev = CpuSample {
+            cpu_usage: 50_000,
+            system_cpu_usage: 10_000,
+        };
+        let current = CpuSample {
+            cpu_usage: 100,
+            system_cpu_usage: 15_000,
+        };
+
+        assert_eq!(cpu_percent(&prev, &current, 2), 0.0);
+    }
+
+    // format_bytes tests
+
+    #[test]
+    fn format_bytes_picks_the_right_unit() {
+        let cases = [
+            (0, "0.0B"),
+            (512, "512.0B"),
+            (1024, "1.0KiB"),
+            (1_536, "1.5KiB"),
+            (1024 * 1024, "1.0MiB"),
+            (1024 * 1024 * 1024, "1.0GiB"),
+            (1024_i64 * 1024 * 1024 * 1024, "1.0TiB"),
+            (1024_i64 * 1024 * 1024 * 1024 * 1024, "1024.0TiB"),
+        ];
+
+        for (bytes, expected) in cases {
+            assert_eq!(format_bytes(bytes), expected, "format_bytes({bytes})");
+        }
+    }
 }
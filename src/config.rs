@@ -3,12 +3,33 @@ use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
     pub url: String,
-    pub api_key: String,
+    /// Plaintext API key. `None` once the secret has been moved to the OS
+    /// keyring (see `unraid config migrate-secrets`); still populated for
+    /// servers added before keyring support, or when the keyring backend
+    /// isn't available on this machine.
+    #[serde(default)]
+    pub api_key: Option<String>,
+}
+
+impl ServerConfig {
+    /// Resolve this server's API key, preferring the OS keyring and falling
+    /// back to the plaintext value stored in the config file.
+    pub fn resolve_api_key(&self, name: &str) -> Result<String> {
+        // A keyring read failure (missing entry, or no keyring backend at
+        // all on this machine) just means we fall back to the config file.
+        if let Ok(Some(key)) = crate::keyring::get(name) {
+            return Ok(key);
+        }
+
+        self.api_key.clone().with_context(|| {
+            format!("No API key found for server '{name}' in the OS keyring or config file")
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -61,7 +82,7 @@ impl Config {
         self.servers.get(server_name)
     }
 
-    pub fn add_server(&mut self, name: String, url: String, api_key: String) {
+    pub fn add_server(&mut self, name: String, url: String, api_key: Option<String>) {
         self.servers.insert(name, ServerConfig { url, api_key });
     }
 
@@ -128,9 +149,19 @@ impl ResolvedConfig {
         let server_name = cli_server.or(env_server.as_deref());
 
         if let Some(server) = config.get_server(server_name) {
+            let api_key = match cli_api_key {
+                Some(key) => key.to_string(),
+                None => {
+                    let resolved_name = server_name
+                        .or(config.default.as_deref())
+                        .context("Failed to resolve server name")?;
+                    server.resolve_api_key(resolved_name)?
+                }
+            };
+
             return Ok(Self {
                 url: cli_url.unwrap_or(&server.url).to_string(),
-                api_key: cli_api_key.unwrap_or(&server.api_key).to_string(),
+                api_key,
             });
         }
 
@@ -141,6 +172,47 @@ impl ResolvedConfig {
     }
 }
 
+/// A single container entry in a [`ComposeFile`] manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComposeContainer {
+    /// Container name, matched against the names Docker reports.
+    pub name: String,
+    /// Whether this container should be running.
+    #[serde(default = "default_true")]
+    pub running: bool,
+    /// Restart policy, informational only for now (not enforced by `up`/`down`).
+    #[serde(default)]
+    pub restart: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Declarative manifest listing the desired state of a set of containers,
+/// reconciled against the live server by `docker compose up`/`down`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ComposeFile {
+    #[serde(default)]
+    pub containers: Vec<ComposeContainer>,
+}
+
+impl ComposeFile {
+    /// Load a manifest from `path`, detecting TOML vs YAML by extension
+    /// (`.yaml`/`.yml` is parsed as YAML, anything else as TOML).
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read compose file: {}", path.display()))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&content)
+                .with_context(|| format!("Failed to parse compose file: {}", path.display())),
+            _ => toml::from_str(&content)
+                .with_context(|| format!("Failed to parse compose file: {}", path.display())),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,12 +222,12 @@ mod tests {
         config.add_server(
             "tower".to_string(),
             "https://192.168.1.100".to_string(),
-            "key-tower".to_string(),
+            Some("key-tower".to_string()),
         );
         config.add_server(
             "backup".to_string(),
             "https://192.168.1.101".to_string(),
-            "key-backup".to_string(),
+            Some("key-backup".to_string()),
         );
         config.default = Some("tower".to_string());
         config
@@ -174,13 +246,13 @@ mod tests {
         config.add_server(
             "test".to_string(),
             "https://example.com".to_string(),
-            "api-key".to_string(),
+            Some("api-key".to_string()),
         );
 
         assert_eq!(config.servers.len(), 1);
         let server = config.servers.get("test").unwrap();
         assert_eq!(server.url, "https://example.com");
-        assert_eq!(server.api_key, "api-key");
+        assert_eq!(server.api_key.as_deref(), Some("api-key"));
     }
 
     #[test]
@@ -189,12 +261,12 @@ mod tests {
         config.add_server(
             "tower".to_string(),
             "https://new-url.com".to_string(),
-            "new-key".to_string(),
+            Some("new-key".to_string()),
         );
 
         let server = config.servers.get("tower").unwrap();
         assert_eq!(server.url, "https://new-url.com");
-        assert_eq!(server.api_key, "new-key");
+        assert_eq!(server.api_key.as_deref(), Some("new-key"));
     }
 
     #[test]
@@ -327,4 +399,32 @@ default = "myserver"
         assert_eq!(resolved.url, "https://cli-url.com");
         assert_eq!(resolved.api_key, "cli-key");
     }
+
+    // ServerConfig::resolve_api_key tests
+
+    #[test]
+    fn resolve_api_key_falls_back_to_plaintext_when_not_in_keyring() {
+        let config = sample_config();
+        let server = config.get_server(Some("tower")).unwrap();
+
+        let key = server
+            .resolve_api_key("unraid-cli-test-server-with-no-keyring-entry")
+            .unwrap();
+
+        assert_eq!(key, "key-tower");
+    }
+
+    #[test]
+    fn resolve_api_key_errors_when_no_key_anywhere() {
+        let server = ServerConfig {
+            url: "https://example.com".to_string(),
+            api_key: None,
+        };
+
+        let err = server
+            .resolve_api_key("unraid-cli-test-server-with-no-key-at-all")
+            .unwrap_err();
+
+        assert!(err.to_string().contains("No API key found"));
+    }
 }
@@ -20,8 +20,56 @@ pub type URL = String;
 #[graphql(
     schema_path = "src/graphql/schema.graphql",
     query_path = "src/graphql/queries/containers.graphql",
-    response_derives = "Debug, Clone, PartialEq, Eq"
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
 )]
 pub struct GetDockerContainers;
 
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/queries/container_mutations.graphql",
+    response_derives = "Debug, Clone, PartialEq, Eq, serde::Serialize"
+)]
+pub struct StartDockerContainer;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/queries/container_mutations.graphql",
+    response_derives = "Debug, Clone, PartialEq, Eq, serde::Serialize"
+)]
+pub struct StopDockerContainer;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/queries/container_mutations.graphql",
+    response_derives = "Debug, Clone, PartialEq, Eq, serde::Serialize"
+)]
+pub struct UpdateDockerContainer;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/queries/container_logs.graphql",
+    response_derives = "Debug, Clone, PartialEq, Eq, serde::Serialize"
+)]
+pub struct GetDockerContainerLogs;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/queries/container_stats.graphql",
+    response_derives = "Debug, Clone, PartialEq, Eq, serde::Serialize"
+)]
+pub struct GetDockerContainerStats;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema.graphql",
+    query_path = "src/graphql/queries/container_inspect.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct GetDockerContainerInspect;
+
 pub use get_docker_containers::*;
@@ -0,0 +1,38 @@
+//! OS keyring storage for server API keys.
+//!
+//! Keys are stored under the service name `unraid-cli` with the server's
+//! config name as the account, so each configured server gets its own
+//! keyring entry.
+
+use anyhow::{Context, Result};
+use keyring::Entry;
+
+const SERVICE: &str = "unraid-cli";
+
+fn entry(server_name: &str) -> Result<Entry> {
+    Entry::new(SERVICE, server_name).context("Failed to open OS keyring entry")
+}
+
+/// Store `api_key` in the OS keyring under `server_name`.
+pub fn store(server_name: &str, api_key: &str) -> Result<()> {
+    entry(server_name)?
+        .set_password(api_key)
+        .context("Failed to write API key to OS keyring")
+}
+
+/// Read the API key for `server_name` from the OS keyring, if one is stored.
+pub fn get(server_name: &str) -> Result<Option<String>> {
+    match entry(server_name)?.get_password() {
+        Ok(password) => Ok(Some(password)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(err) => Err(err).context("Failed to read API key from OS keyring"),
+    }
+}
+
+/// Remove the API key for `server_name` from the OS keyring, if one is stored.
+pub fn delete(server_name: &str) -> Result<()> {
+    match entry(server_name)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(err) => Err(err).context("Failed to delete API key from OS keyring"),
+    }
+}
@@ -0,0 +1,43 @@
+//! `tracing` subscriber setup, initialized once in `main` from the global
+//! `-v`/`--log-format` flags.
+
+use clap::ValueEnum;
+use tracing_subscriber::EnvFilter;
+
+/// Log output shape selected by the global `--log-format` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable, colored output (default).
+    #[default]
+    Pretty,
+    Json,
+}
+
+/// Initialize the global `tracing` subscriber.
+///
+/// `verbosity` is the repeat count of `-v`: 0 shows warnings and errors
+/// only, 1 adds info, 2 adds debug, and 3+ adds trace (including the full
+/// GraphQL request/response bodies logged by [`crate::client::UnraidClient`]).
+/// `RUST_LOG` overrides this when set.
+pub fn init(verbosity: u8, format: LogFormat) {
+    let default_level = match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_level));
+
+    match format {
+        LogFormat::Pretty => {
+            tracing_subscriber::fmt().with_env_filter(filter).init();
+        }
+        LogFormat::Json => {
+            tracing_subscriber::fmt()
+                .json()
+                .with_env_filter(filter)
+                .init();
+        }
+    }
+}
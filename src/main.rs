@@ -4,14 +4,22 @@ mod client;
 mod commands;
 mod config;
 mod graphql;
+mod keyring;
+mod logging;
+mod output;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 
-use crate::client::UnraidClient;
+use crate::client::{RetryConfig, UnraidClient};
 use crate::commands::config::{ConfigCommands, handle_config_command};
 use crate::commands::docker::{DockerCommands, handle_docker_command};
 use crate::config::ResolvedConfig;
+use crate::logging::LogFormat;
+use crate::output::OutputFormat;
+
+/// Default request timeout, in seconds, for [`UnraidClient`].
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
 
 #[derive(Parser)]
 #[command(name = "unraid")]
@@ -30,6 +38,34 @@ struct Cli {
     #[arg(long, global = true, env = "UNRAID_API_KEY")]
     api_key: Option<String>,
 
+    /// Output format
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+
+    /// Show API keys in full instead of masked (applies to `config list`)
+    #[arg(long, global = true)]
+    reveal_secrets: bool,
+
+    /// Increase log verbosity (-v for info, -vv for debug, -vvv for trace)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Log output format
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Pretty)]
+    log_format: LogFormat,
+
+    /// Request timeout in seconds
+    #[arg(long, global = true, default_value_t = DEFAULT_TIMEOUT_SECS)]
+    timeout: u64,
+
+    /// Maximum attempts for a GraphQL request before giving up (1 disables retrying)
+    #[arg(long, global = true, default_value_t = RetryConfig::default().max_attempts)]
+    max_retries: u32,
+
+    /// Base delay in milliseconds for retry backoff, doubled on each attempt
+    #[arg(long, global = true, default_value_t = RetryConfig::default().base_delay_ms)]
+    retry_base_delay_ms: u64,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -51,10 +87,11 @@ enum Commands {
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    logging::init(cli.verbose, cli.log_format);
 
     match cli.command {
         Commands::Config { command } => {
-            handle_config_command(command)?;
+            handle_config_command(command, cli.output, cli.reveal_secrets)?;
         }
         Commands::Docker { command } => {
             let resolved = ResolvedConfig::resolve(
@@ -63,8 +100,12 @@ async fn main() -> Result<()> {
                 cli.api_key.as_deref(),
             )?;
 
-            let client = UnraidClient::new(resolved.url, resolved.api_key)?;
-            handle_docker_command(command, &client).await?;
+            let retry = RetryConfig {
+                max_attempts: cli.max_retries,
+                base_delay_ms: cli.retry_base_delay_ms,
+            };
+            let client = UnraidClient::new(resolved.url, resolved.api_key, cli.timeout, retry)?;
+            handle_docker_command(command, &client, cli.output).await?;
         }
     }
 
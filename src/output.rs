@@ -0,0 +1,39 @@
+//! Structured output rendering shared by every command.
+//!
+//! Commands fetch their data and then render it through [`OutputFormat`]
+//! instead of `println!`-ing ad hoc strings, so `--output json|yaml` works
+//! uniformly without each command reimplementing serialization.
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// Output format shared by all commands via the global `--output` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable table (default).
+    #[default]
+    Table,
+    Json,
+    Yaml,
+}
+
+/// Render `value` as JSON or YAML. Callers handle `OutputFormat::Table`
+/// themselves, since table layout is specific to each command's data.
+pub fn render<T: Serialize>(value: &T, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => {
+            let rendered = serde_json::to_string_pretty(value).context("Failed to render JSON")?;
+            println!("{rendered}");
+        }
+        OutputFormat::Yaml => {
+            let rendered = serde_yaml::to_string(value).context("Failed to render YAML")?;
+            print!("{rendered}");
+        }
+        OutputFormat::Table => {
+            anyhow::bail!("render() does not handle table output; render it directly instead");
+        }
+    }
+
+    Ok(())
+}